@@ -176,6 +176,510 @@ pub trait BidirIterator {
     {
         FilterMap { iter: self, f }
     }
+
+    /// Reverse the direction of iteration, so that `next()` yields what
+    /// `prev()` would have yielded and vice versa.
+    ///
+    /// # Examples
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3];
+    /// let mut iter = a.bidir_iter();
+    /// iter.forward().count(); // drive the cursor to the far end
+    /// let mut iter = iter.rev();
+    ///
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.prev(), Some(&1));
+    /// assert_eq!(iter.prev(), Some(&2));
+    /// assert_eq!(iter.prev(), Some(&3));
+    /// assert_eq!(iter.prev(), None);
+    /// ```
+    ///
+    /// `rev().rev()` round-trips back to the original direction:
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3];
+    /// let mut iter = a.bidir_iter().rev().rev();
+    ///
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// The `next`/`prev` contract still holds through the swap: after
+    /// driving all the way in one direction, reversing and driving all
+    /// the way back yields the same elements in the opposite order.
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3];
+    /// let mut iter = a.bidir_iter().rev();
+    ///
+    /// assert_eq!(iter.prev(), Some(&1));
+    /// assert_eq!(iter.prev(), Some(&2));
+    /// assert_eq!(iter.prev(), Some(&3));
+    /// assert_eq!(iter.prev(), None);
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn rev(self) -> Rev<Self>
+    where
+        Self: Sized,
+    {
+        Rev { iter: self }
+    }
+
+    /// Pair each item with its position in the sequence, as `(usize, Item)`.
+    ///
+    /// Because the cursor sits between elements, the index tracks the
+    /// cursor rather than a monotonic counter: after yielding the element
+    /// logically at position `i`, the emitted index is `i` whether it was
+    /// reached via `next` or `prev`.
+    ///
+    /// # Examples
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[10, 20, 30];
+    /// let mut iter = a.bidir_iter().enumerate();
+    ///
+    /// assert_eq!(iter.next(), Some((0, &10)));
+    /// assert_eq!(iter.next(), Some((1, &20)));
+    /// assert_eq!(iter.next(), Some((2, &30)));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// assert_eq!(iter.prev(), Some((2, &30)));
+    /// assert_eq!(iter.prev(), Some((1, &20)));
+    /// assert_eq!(iter.prev(), Some((0, &10)));
+    /// assert_eq!(iter.prev(), None);
+    /// assert_eq!(iter.next(), Some((0, &10)));
+    /// ```
+    fn enumerate(self) -> Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        Enumerate {
+            iter: self,
+            count: 0,
+        }
+    }
+
+    /// Chain `self` with `other`, traversing all of `self` before `other`
+    /// going forward, and all of `other` before `self` going backward.
+    ///
+    /// # Examples
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2];
+    /// let b: &[i64] = &[3, 4];
+    /// let mut iter = a.bidir_iter().chain(b.bidir_iter());
+    ///
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), Some(&4));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// Crossing the seam in reverse lands on the last element of `self`,
+    /// not `None`:
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2];
+    /// let b: &[i64] = &[3, 4];
+    /// let mut iter = a.bidir_iter().chain(b.bidir_iter());
+    ///
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.prev(), Some(&2));
+    /// assert_eq!(iter.prev(), Some(&1));
+    /// assert_eq!(iter.prev(), None);
+    /// ```
+    ///
+    /// The seam can be bounced across repeatedly without losing elements:
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2];
+    /// let b: &[i64] = &[3, 4];
+    /// let mut iter = a.bidir_iter().chain(b.bidir_iter());
+    ///
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.prev(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.prev(), Some(&2));
+    /// assert_eq!(iter.prev(), Some(&1));
+    /// ```
+    fn chain<U>(self, other: U) -> Chain<Self, U>
+    where
+        Self: Sized,
+        U: BidirIterator<Item = Self::Item>,
+    {
+        Chain {
+            a: self,
+            b: other,
+            state: ChainState::First,
+        }
+    }
+
+    /// Pair elements of `self` and `other`, advancing and retreating both
+    /// in lockstep. Stops at the shorter side, in both directions.
+    ///
+    /// # Examples
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3];
+    /// let b: &[i64] = &[10, 20, 30];
+    /// let mut iter = a.bidir_iter().zip(b.bidir_iter());
+    ///
+    /// assert_eq!(iter.next(), Some((&1, &10)));
+    /// assert_eq!(iter.next(), Some((&2, &20)));
+    /// assert_eq!(iter.next(), Some((&3, &30)));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// assert_eq!(iter.prev(), Some((&3, &30)));
+    /// assert_eq!(iter.prev(), Some((&2, &20)));
+    /// assert_eq!(iter.prev(), Some((&1, &10)));
+    /// assert_eq!(iter.prev(), None);
+    /// ```
+    ///
+    /// With unequal lengths, a full forward-then-backward pass is
+    /// symmetric: the cursor doesn't drift relative to the shorter stream,
+    /// no matter how many times `next()` is called past exhaustion.
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3, 4];
+    /// let b: &[i64] = &[10, 20];
+    /// let mut iter = a.bidir_iter().zip(b.bidir_iter());
+    ///
+    /// assert_eq!(iter.next(), Some((&1, &10)));
+    /// assert_eq!(iter.next(), Some((&2, &20)));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// assert_eq!(iter.prev(), Some((&2, &20)));
+    /// assert_eq!(iter.prev(), Some((&1, &10)));
+    /// assert_eq!(iter.prev(), None);
+    /// ```
+    ///
+    /// The no-drift guarantee doesn't depend on the shorter side being a
+    /// bare slice iterator; it holds just as well composed with another
+    /// adaptor:
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3, 4];
+    /// let b: &[i64] = &[10, 20, 30];
+    /// let mut iter = a.bidir_iter().zip(b.bidir_iter().take(2));
+    ///
+    /// assert_eq!(iter.next(), Some((&1, &10)));
+    /// assert_eq!(iter.next(), Some((&2, &20)));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// assert_eq!(iter.prev(), Some((&2, &20)));
+    /// assert_eq!(iter.prev(), Some((&1, &10)));
+    /// assert_eq!(iter.prev(), None);
+    /// ```
+    fn zip<U>(self, other: U) -> Zip<Self, U>
+    where
+        Self: Sized,
+        U: BidirIterator,
+    {
+        Zip {
+            a: self,
+            b: other,
+            state: ZipState::Live,
+            ahead: Ahead::Neither,
+        }
+    }
+
+    /// Wrap this iterator so that `peek_next`/`peek_prev` can report the
+    /// next or previous element without moving the cursor, exploiting the
+    /// `next`/`prev` contract that a reversal immediately undoes a step.
+    ///
+    /// # Examples
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3];
+    /// let mut iter = a.bidir_iter().peekable();
+    ///
+    /// assert_eq!(iter.peek_next(), Some(&&1));
+    /// assert_eq!(iter.peek_next(), Some(&&1));
+    /// assert_eq!(iter.next(), Some(&1));
+    /// ```
+    ///
+    /// Peeking doesn't desync a later `prev`:
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3];
+    /// let mut iter = a.bidir_iter().peekable();
+    ///
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// assert_eq!(iter.peek_prev(), Some(&&3));
+    /// assert_eq!(iter.peek_prev(), Some(&&3));
+    /// assert_eq!(iter.prev(), Some(&3));
+    /// assert_eq!(iter.prev(), Some(&2));
+    /// assert_eq!(iter.prev(), Some(&1));
+    /// assert_eq!(iter.prev(), None);
+    /// ```
+    fn peekable(self) -> Peekable<Self>
+    where
+        Self: Sized,
+    {
+        Peekable {
+            iter: self,
+            peeked_next: None,
+            peeked_prev: None,
+        }
+    }
+
+    /// Skip the first `n` elements going forward. `prev()` stops at the
+    /// skip boundary rather than surfacing the skipped elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3, 4, 5];
+    /// let mut iter = a.bidir_iter().skip(2);
+    ///
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), Some(&4));
+    /// assert_eq!(iter.next(), Some(&5));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// assert_eq!(iter.prev(), Some(&5));
+    /// assert_eq!(iter.prev(), Some(&4));
+    /// assert_eq!(iter.prev(), Some(&3));
+    /// assert_eq!(iter.prev(), None);
+    /// ```
+    fn skip(self, n: usize) -> Skip<Self>
+    where
+        Self: Sized,
+    {
+        Skip {
+            iter: self,
+            n,
+            skipped: false,
+            ahead: 0,
+        }
+    }
+
+    /// Yield at most `n` elements going forward. `prev()` can retreat back
+    /// through the taken window, but not past its start.
+    ///
+    /// # Examples
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3, 4, 5];
+    /// let mut iter = a.bidir_iter().take(3);
+    ///
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// assert_eq!(iter.prev(), Some(&3));
+    /// assert_eq!(iter.prev(), Some(&2));
+    /// assert_eq!(iter.prev(), Some(&1));
+    /// assert_eq!(iter.prev(), None);
+    /// ```
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take {
+            iter: self,
+            n,
+            remaining: n,
+            exhausted: false,
+        }
+    }
+
+    /// Yield every `step`-th element. A `next()` followed by a `prev()` lands
+    /// back on the same strided element.
+    ///
+    /// # Panics
+    /// Panics if `step` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3, 4, 5, 6];
+    /// let mut iter = a.bidir_iter().step_by(2);
+    ///
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), Some(&5));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// assert_eq!(iter.prev(), Some(&5));
+    /// assert_eq!(iter.prev(), Some(&3));
+    /// assert_eq!(iter.prev(), Some(&1));
+    /// assert_eq!(iter.prev(), None);
+    /// ```
+    ///
+    /// The source length need not be an exact multiple of `step`:
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3, 4, 5];
+    /// let mut iter = a.bidir_iter().step_by(2);
+    ///
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), Some(&5));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// assert_eq!(iter.prev(), Some(&5));
+    /// assert_eq!(iter.prev(), Some(&3));
+    /// assert_eq!(iter.prev(), Some(&1));
+    /// assert_eq!(iter.prev(), None);
+    /// ```
+    fn step_by(self, step: usize) -> StepBy<Self>
+    where
+        Self: Sized,
+    {
+        assert!(step != 0, "step_by: step must be non-zero");
+        StepBy {
+            iter: self,
+            step,
+            exhausted: false,
+            overshoot: 0,
+        }
+    }
+
+    /// Drive `next()` to exhaustion, folding each item into an accumulator.
+    ///
+    /// # Examples
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3];
+    /// let s = a.bidir_iter().fold(String::new(), |acc, x| acc + &x.to_string());
+    /// assert_eq!(s, "123");
+    /// ```
+    fn fold<Acc, F>(mut self, init: Acc, mut f: F) -> Acc
+    where
+        Self: Sized,
+        F: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        while let Some(item) = self.next() {
+            acc = f(acc, item);
+        }
+        acc
+    }
+
+    /// Drive `prev()` to exhaustion, folding each item into an accumulator.
+    ///
+    /// `fold` and `rfold` over the same source, once driven to the far end,
+    /// produce order-reversed reductions:
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3];
+    /// let mut iter = a.bidir_iter();
+    /// iter.forward().count(); // drive to the far end
+    ///
+    /// let s = iter.rfold(String::new(), |acc, x| acc + &x.to_string());
+    /// assert_eq!(s, "321");
+    /// ```
+    fn rfold<Acc, F>(mut self, init: Acc, mut f: F) -> Acc
+    where
+        Self: Sized,
+        F: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        while let Some(item) = self.prev() {
+            acc = f(acc, item);
+        }
+        acc
+    }
+
+    /// Count the remaining elements going forward.
+    ///
+    /// # Examples
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3, 4];
+    /// assert_eq!(a.bidir_iter().count(), 4);
+    /// ```
+    fn count(self) -> usize
+    where
+        Self: Sized,
+    {
+        self.fold(0, |acc, _| acc + 1)
+    }
+
+    /// Advance `n` elements forward, then return the next one.
+    ///
+    /// # Examples
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3, 4, 5];
+    /// let mut iter = a.bidir_iter();
+    ///
+    /// assert_eq!(iter.nth(1), Some(&2));
+    /// assert_eq!(iter.prev(), Some(&1));
+    /// ```
+    fn nth(&mut self, n: usize) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        for _ in 0..n {
+            self.next()?;
+        }
+        self.next()
+    }
+
+    /// Retreat `n` elements backward, then return the previous one.
+    ///
+    /// # Examples
+    /// ```
+    /// use bidir_iter::*;
+    ///
+    /// let a: &[i64] = &[1, 2, 3, 4, 5];
+    /// let mut iter = a.bidir_iter();
+    /// iter.forward().count(); // drive to the far end
+    ///
+    /// assert_eq!(iter.nth_back(1), Some(&4));
+    /// assert_eq!(iter.next(), Some(&5));
+    /// ```
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        for _ in 0..n {
+            self.prev()?;
+        }
+        self.prev()
+    }
 }
 
 impl<T> BidirIterator for &mut T
@@ -300,3 +804,379 @@ where
         None
     }
 }
+
+pub struct Rev<B> {
+    iter: B,
+}
+
+impl<B: BidirIterator> BidirIterator for Rev<B> {
+    type Item = B::Item;
+
+    fn next(&mut self) -> Option<B::Item> {
+        self.iter.prev()
+    }
+
+    fn prev(&mut self) -> Option<B::Item> {
+        self.iter.next()
+    }
+}
+
+pub struct Enumerate<B> {
+    iter: B,
+    count: usize,
+}
+
+impl<B: BidirIterator> BidirIterator for Enumerate<B> {
+    type Item = (usize, B::Item);
+
+    fn next(&mut self) -> Option<(usize, B::Item)> {
+        let item = self.iter.next()?;
+        let i = self.count;
+        self.count += 1;
+        Some((i, item))
+    }
+
+    fn prev(&mut self) -> Option<(usize, B::Item)> {
+        let item = self.iter.prev()?;
+        self.count -= 1;
+        Some((self.count, item))
+    }
+}
+
+enum ChainState {
+    First,
+    Second,
+}
+
+pub struct Chain<A, B> {
+    a: A,
+    b: B,
+    state: ChainState,
+}
+
+impl<A, B> BidirIterator for Chain<A, B>
+where
+    A: BidirIterator,
+    B: BidirIterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        match self.state {
+            ChainState::First => match self.a.next() {
+                Some(item) => Some(item),
+                None => {
+                    self.state = ChainState::Second;
+                    self.b.next()
+                }
+            },
+            ChainState::Second => self.b.next(),
+        }
+    }
+
+    fn prev(&mut self) -> Option<A::Item> {
+        match self.state {
+            ChainState::Second => match self.b.prev() {
+                Some(item) => Some(item),
+                None => {
+                    self.state = ChainState::First;
+                    self.a.prev()
+                }
+            },
+            ChainState::First => self.a.prev(),
+        }
+    }
+}
+
+enum ZipState {
+    Live,
+    Exhausted,
+}
+
+// Which side was still yielding when the other one ran out, and how many
+// items it yielded past the last matched pair (its final next() call, the
+// one that returned None, is not counted). A plain slice's
+// BiIter happens to keep its cursor in step with a failed call for free,
+// but that's not part of the BidirIterator contract, so Zip can't assume
+// it: it has to track the overrun itself and unwind exactly that many
+// steps before the paired prev() can recover the last matched pair.
+enum Ahead {
+    Neither,
+    A(usize),
+    B(usize),
+}
+
+pub struct Zip<A, B> {
+    a: A,
+    b: B,
+    state: ZipState,
+    ahead: Ahead,
+}
+
+impl<A, B> BidirIterator for Zip<A, B>
+where
+    A: BidirIterator,
+    B: BidirIterator,
+{
+    type Item = (A::Item, B::Item);
+
+    fn next(&mut self) -> Option<(A::Item, B::Item)> {
+        if let ZipState::Exhausted = self.state {
+            return None;
+        }
+        self.state = ZipState::Exhausted;
+        match (self.a.next(), self.b.next()) {
+            (Some(a), Some(b)) => {
+                self.state = ZipState::Live;
+                Some((a, b))
+            }
+            (Some(_), None) => {
+                let mut overrun = 1;
+                while self.a.next().is_some() {
+                    overrun += 1;
+                }
+                self.ahead = Ahead::A(overrun);
+                None
+            }
+            (None, Some(_)) => {
+                let mut overrun = 1;
+                while self.b.next().is_some() {
+                    overrun += 1;
+                }
+                self.ahead = Ahead::B(overrun);
+                None
+            }
+            (None, None) => {
+                self.ahead = Ahead::Neither;
+                None
+            }
+        }
+    }
+
+    fn prev(&mut self) -> Option<(A::Item, B::Item)> {
+        self.state = ZipState::Live;
+        match std::mem::replace(&mut self.ahead, Ahead::Neither) {
+            Ahead::A(overrun) => {
+                for _ in 0..overrun {
+                    self.a.prev();
+                }
+            }
+            Ahead::B(overrun) => {
+                for _ in 0..overrun {
+                    self.b.prev();
+                }
+            }
+            Ahead::Neither => {}
+        }
+        match (self.a.prev(), self.b.prev()) {
+            (Some(a), Some(b)) => Some((a, b)),
+            _ => None,
+        }
+    }
+}
+
+pub struct Peekable<B: BidirIterator> {
+    iter: B,
+    peeked_next: Option<Option<B::Item>>,
+    peeked_prev: Option<Option<B::Item>>,
+}
+
+impl<B: BidirIterator> Peekable<B> {
+    /// Return the element that a following `next()` would yield, without
+    /// moving the cursor.
+    pub fn peek_next(&mut self) -> Option<&B::Item> {
+        if self.peeked_next.is_none() {
+            let item = self.iter.next();
+            if item.is_some() {
+                self.iter.prev();
+            }
+            self.peeked_next = Some(item);
+        }
+        self.peeked_next.as_ref().unwrap().as_ref()
+    }
+
+    /// Return the element that a following `prev()` would yield, without
+    /// moving the cursor.
+    pub fn peek_prev(&mut self) -> Option<&B::Item> {
+        if self.peeked_prev.is_none() {
+            let item = self.iter.prev();
+            if item.is_some() {
+                self.iter.next();
+            }
+            self.peeked_prev = Some(item);
+        }
+        self.peeked_prev.as_ref().unwrap().as_ref()
+    }
+}
+
+impl<B: BidirIterator> BidirIterator for Peekable<B> {
+    type Item = B::Item;
+
+    fn next(&mut self) -> Option<B::Item> {
+        self.peeked_prev = None;
+        match self.peeked_next.take() {
+            Some(item) => {
+                if item.is_some() {
+                    self.iter.next();
+                }
+                item
+            }
+            None => self.iter.next(),
+        }
+    }
+
+    fn prev(&mut self) -> Option<B::Item> {
+        self.peeked_next = None;
+        match self.peeked_prev.take() {
+            Some(item) => {
+                if item.is_some() {
+                    self.iter.prev();
+                }
+                item
+            }
+            None => self.iter.prev(),
+        }
+    }
+}
+
+pub struct Skip<B> {
+    iter: B,
+    n: usize,
+    skipped: bool,
+    ahead: usize,
+}
+
+impl<B: BidirIterator> BidirIterator for Skip<B> {
+    type Item = B::Item;
+
+    fn next(&mut self) -> Option<B::Item> {
+        if !self.skipped {
+            for _ in 0..self.n {
+                if self.iter.next().is_none() {
+                    break;
+                }
+            }
+            self.skipped = true;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                self.ahead += 1;
+                Some(item)
+            }
+            None => None,
+        }
+    }
+
+    fn prev(&mut self) -> Option<B::Item> {
+        if self.ahead == 0 {
+            return None;
+        }
+        match self.iter.prev() {
+            Some(item) => {
+                self.ahead -= 1;
+                Some(item)
+            }
+            None => None,
+        }
+    }
+}
+
+pub struct Take<B> {
+    iter: B,
+    n: usize,
+    remaining: usize,
+    exhausted: bool,
+}
+
+impl<B: BidirIterator> BidirIterator for Take<B> {
+    type Item = B::Item;
+
+    fn next(&mut self) -> Option<B::Item> {
+        if self.remaining == 0 {
+            // Forward to the inner iterator once, even though we're about
+            // to discard whatever it gives us: prev()'s recovery of the
+            // last taken element relies on the inner cursor having taken
+            // this extra step, same as every other adaptor in this file.
+            if !self.exhausted {
+                self.iter.next();
+                self.exhausted = true;
+            }
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                self.remaining -= 1;
+                Some(item)
+            }
+            None => None,
+        }
+    }
+
+    fn prev(&mut self) -> Option<B::Item> {
+        if self.remaining == self.n {
+            return None;
+        }
+        match self.iter.prev() {
+            Some(item) => {
+                self.remaining += 1;
+                self.exhausted = false;
+                Some(item)
+            }
+            None => None,
+        }
+    }
+}
+
+pub struct StepBy<B> {
+    iter: B,
+    step: usize,
+    // Whether the inner iterator has already run dry for this stride.
+    exhausted: bool,
+    // Inner next() calls made after the call that yielded the last emitted
+    // element. Once exhausted, prev() undoes exactly this many calls with
+    // inner prev(), the last of which yields that element again; the count
+    // differs depending on whether the source length is an exact multiple
+    // of `step`.
+    overshoot: usize,
+}
+
+impl<B: BidirIterator> BidirIterator for StepBy<B> {
+    type Item = B::Item;
+
+    fn next(&mut self) -> Option<B::Item> {
+        if self.exhausted {
+            return None;
+        }
+        let item = self.iter.next();
+        if item.is_some() {
+            self.overshoot = 0;
+            for _ in 0..self.step - 1 {
+                self.overshoot += 1;
+                if self.iter.next().is_none() {
+                    self.exhausted = true;
+                    break;
+                }
+            }
+        } else {
+            self.overshoot += 1;
+            self.exhausted = true;
+        }
+        item
+    }
+
+    fn prev(&mut self) -> Option<B::Item> {
+        if self.exhausted {
+            let mut item = None;
+            for _ in 0..self.overshoot {
+                item = self.iter.prev();
+            }
+            self.exhausted = false;
+            self.overshoot = 0;
+            return item;
+        }
+        for _ in 0..self.step - 1 {
+            self.iter.prev()?;
+        }
+        self.iter.prev()
+    }
+}